@@ -1,17 +1,18 @@
 // Learn more about Tauri commands at https://tauri.app/develop/calling-rust/
 use anyhow::Context;
+use base64::{engine::general_purpose, Engine as _};
 use clipboard_rs::{
     common::RustImage, Clipboard, ClipboardContext, ClipboardHandler, ClipboardWatcher,
-    ClipboardWatcherContext, ContentFormat,
+    ClipboardWatcherContext, ContentFormat, RustImageData,
 };
 use serde::{Deserialize, Serialize};
 use std::{
     fs,
     path::PathBuf,
     sync::Arc,
-    time::{SystemTime, UNIX_EPOCH},
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
-use tauri::{Emitter, Manager, State};
+use tauri::{Emitter, Listener, Manager, State};
 use thiserror::Error;
 use tokio::sync::Mutex;
 use tracing::{debug, error, info};
@@ -33,6 +34,9 @@ pub enum ClipboardError {
 
     #[error("事件发送失败: {0}")]
     EventError(String),
+
+    #[error("同步失败: {0}")]
+    SyncError(String),
 }
 
 // 为ClipboardError实现Serialize trait，使其可以在Tauri命令中作为错误返回
@@ -55,7 +59,12 @@ pub struct ClipboardItem {
     html_content: Option<String>,
     rtf_content: Option<String>,
     image_path: Option<String>,
+    file_paths: Option<Vec<String>>,
     timestamp: u64,
+    source: ClipboardSource,
+    kind: ClipboardKind,
+    // 剪贴板当前持有的所有原始格式标识，用于调试及读写 typed 路径不理解的格式
+    formats: Vec<String>,
 }
 
 // 剪贴板内容类型枚举
@@ -70,10 +79,80 @@ pub enum ClipboardContentType {
     Unknown,
 }
 
+// 剪贴板条目来源：本地产生还是从对端同步而来
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum ClipboardSource {
+    Local,
+    Remote,
+}
+
+// 剪贴板目标种类：常规剪贴板，或 X11 下的 Primary Selection（中键粘贴）
+// 在 Windows/macOS 上不存在 Primary Selection，相关请求会透明回退到常规剪贴板
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum ClipboardKind {
+    Clipboard,
+    Primary,
+}
+
+impl Default for ClipboardKind {
+    fn default() -> Self {
+        ClipboardKind::Clipboard
+    }
+}
+
+// 读取 X11 Primary Selection（中键粘贴的来源）
+#[cfg(target_os = "linux")]
+fn read_primary_selection() -> Result<String, ClipboardError> {
+    use x11_clipboard::Clipboard as X11Clipboard;
+
+    let clipboard = X11Clipboard::new().map_err(|e| ClipboardError::InitError(e.to_string()))?;
+    let atoms = &clipboard.getter.atoms;
+    let data = clipboard
+        .load(
+            atoms.primary,
+            atoms.utf8_string,
+            atoms.property,
+            Duration::from_secs(3),
+        )
+        .map_err(|e| ClipboardError::ReadError(e.to_string()))?;
+
+    String::from_utf8(data).map_err(|e| ClipboardError::ReadError(e.to_string()))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_primary_selection() -> Result<String, ClipboardError> {
+    Err(ClipboardError::ReadError(
+        "当前平台不支持 Primary Selection".to_string(),
+    ))
+}
+
+// 写入 X11 Primary Selection
+#[cfg(target_os = "linux")]
+fn write_primary_selection(text: String) -> Result<(), ClipboardError> {
+    use x11_clipboard::Clipboard as X11Clipboard;
+
+    let clipboard = X11Clipboard::new().map_err(|e| ClipboardError::InitError(e.to_string()))?;
+    let atoms = &clipboard.setter.atoms;
+    clipboard
+        .store(atoms.primary, atoms.utf8_string, text.into_bytes())
+        .map_err(|e| ClipboardError::WriteError(e.to_string()))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn write_primary_selection(_text: String) -> Result<(), ClipboardError> {
+    Err(ClipboardError::WriteError(
+        "当前平台不支持 Primary Selection".to_string(),
+    ))
+}
+
 // 存储剪贴板历史记录的状态
 pub struct ClipboardState {
     history: Vec<ClipboardItem>,
     last_content_hash: Option<u64>,
+    // Primary Selection 的最近内容哈希，与常规剪贴板分开去重
+    last_primary_hash: Option<u64>,
     next_id: usize,
     // 配置选项
     max_history_size: usize,
@@ -84,6 +163,7 @@ impl Default for ClipboardState {
         Self {
             history: Vec::new(),
             last_content_hash: None,
+            last_primary_hash: None,
             next_id: 0,
             max_history_size: 100, // 默认最多保存100条记录
         }
@@ -101,8 +181,12 @@ impl ClipboardState {
             self.history.remove(0);
         }
 
-        // 更新ID和哈希值
-        self.last_content_hash = Some(self.calculate_hash(&item));
+        // 更新ID和哈希值（按剪贴板目标分别去重）
+        let hash = self.calculate_hash(&item);
+        match item.kind {
+            ClipboardKind::Clipboard => self.last_content_hash = Some(hash),
+            ClipboardKind::Primary => self.last_primary_hash = Some(hash),
+        }
         self.next_id += 1;
 
         item
@@ -124,6 +208,9 @@ impl ClipboardState {
         if let Some(img_path) = &item.image_path {
             img_path.hash(&mut hasher);
         }
+        if let Some(file_paths) = &item.file_paths {
+            file_paths.hash(&mut hasher);
+        }
         hasher.finish()
     }
 
@@ -135,6 +222,9 @@ impl ClipboardState {
         html_content: Option<String>,
         rtf_content: Option<String>,
         image_path: Option<String>,
+        file_paths: Option<Vec<String>>,
+        kind: ClipboardKind,
+        formats: Vec<String>,
     ) -> ClipboardItem {
         let now = SystemTime::now()
             .duration_since(UNIX_EPOCH)
@@ -148,7 +238,11 @@ impl ClipboardState {
             html_content,
             rtf_content,
             image_path,
+            file_paths,
             timestamp: now,
+            source: ClipboardSource::Local,
+            kind,
+            formats,
         }
     }
 }
@@ -189,10 +283,34 @@ impl ClipboardStateManager {
         let mut html_content = None;
         let mut rtf_content = None;
         let mut image_path = None;
+        let mut file_paths = None;
         let mut content_type = ClipboardContentType::Unknown;
 
         // 确定内容类型
-        if self.ctx.has(ContentFormat::Image) {
+        if self.ctx.has(ContentFormat::Files) {
+            content_type = ClipboardContentType::File;
+            // 读取文件列表
+            match self.ctx.get_files() {
+                Ok(paths) => {
+                    // 生成人类可读的摘要：以文件名（不含路径）拼接
+                    let names: Vec<String> = paths
+                        .iter()
+                        .map(|p| {
+                            PathBuf::from(p)
+                                .file_name()
+                                .map(|n| n.to_string_lossy().to_string())
+                                .unwrap_or_else(|| p.clone())
+                        })
+                        .collect();
+                    text = names.join(", ");
+                    file_paths = Some(paths);
+                }
+                Err(e) => {
+                    error!("读取文件列表失败: {}", e);
+                    text = "[文件内容-读取失败]".to_string();
+                }
+            }
+        } else if self.ctx.has(ContentFormat::Image) {
             content_type = ClipboardContentType::Image;
             // 处理图片
             match self.ctx.get_image() {
@@ -255,9 +373,21 @@ impl ClipboardStateManager {
             }
         }
 
+        // 记录当前剪贴板持有的所有原始格式，便于调试
+        let formats = self.ctx.available_formats().unwrap_or_default();
+
         // 创建新条目
         let mut state = self.state.lock().await;
-        let new_item = state.create_item(text, content_type, html_content, rtf_content, image_path);
+        let new_item = state.create_item(
+            text,
+            content_type,
+            html_content,
+            rtf_content,
+            image_path,
+            file_paths,
+            ClipboardKind::Clipboard,
+            formats,
+        );
 
         // 计算内容哈希值检查是否变化
         let hash = state.calculate_hash(&new_item);
@@ -295,6 +425,275 @@ impl ClipboardHandler for ClipboardStateManager {
     }
 }
 
+// 同步对端的连接配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncConfig {
+    endpoint: String,
+    token: Option<String>,
+    poll_interval_secs: u64,
+}
+
+// 在网络上传输的剪贴板内容负载
+// 与 ClipboardItem 的区别在于图片以 base64 内联传输，而不是磁盘路径
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SyncPayload {
+    content: String,
+    content_type: ClipboardContentType,
+    html_content: Option<String>,
+    rtf_content: Option<String>,
+    image_base64: Option<String>,
+}
+
+// 剪贴板网络同步器：负责把本地变化推送给对端，并轮询对端的最新内容
+struct ClipboardSync {
+    state: Arc<Mutex<ClipboardState>>,
+    app: tauri::AppHandle,
+    client: reqwest::Client,
+    config: Arc<Mutex<Option<SyncConfig>>>,
+}
+
+impl ClipboardSync {
+    fn new(state: Arc<Mutex<ClipboardState>>, app: tauri::AppHandle) -> Self {
+        Self {
+            state,
+            app,
+            client: reqwest::Client::new(),
+            config: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    // 构建带鉴权信息的请求
+    fn authed(
+        &self,
+        builder: reqwest::RequestBuilder,
+        config: &SyncConfig,
+    ) -> reqwest::RequestBuilder {
+        match &config.token {
+            Some(token) => builder.bearer_auth(token),
+            None => builder,
+        }
+    }
+
+    // 把本地新增的条目推送给对端
+    async fn push_item(&self, item: &ClipboardItem) -> Result<(), ClipboardError> {
+        let config = self.config.lock().await.clone();
+        let Some(config) = config else {
+            return Ok(()); // 未配置同步，忽略
+        };
+
+        let image_base64 = match &item.image_path {
+            Some(image_name) => {
+                let app_data_dir = self
+                    .app
+                    .path()
+                    .app_data_dir()
+                    .map_err(|e| ClipboardError::SyncError(format!("无法获取应用数据目录: {}", e)))?;
+                let path = app_data_dir.join("clipboard_images").join(image_name);
+                let bytes = fs::read(&path)
+                    .map_err(|e| ClipboardError::SyncError(format!("读取图片文件失败: {}", e)))?;
+                Some(general_purpose::STANDARD.encode(bytes))
+            }
+            None => None,
+        };
+
+        let payload = SyncPayload {
+            content: item.content.clone(),
+            content_type: item.content_type.clone(),
+            html_content: item.html_content.clone(),
+            rtf_content: item.rtf_content.clone(),
+            image_base64,
+        };
+
+        let request = self
+            .client
+            .post(format!("{}/items", config.endpoint))
+            .json(&payload);
+        self.authed(request, &config)
+            .send()
+            .await
+            .map_err(|e| ClipboardError::SyncError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    // 从对端拉取最新内容，如果与本地已知内容不同则写入系统剪贴板
+    async fn poll_once(&self) -> Result<(), ClipboardError> {
+        let config = self.config.lock().await.clone();
+        let Some(config) = config else {
+            return Ok(()); // 未配置同步，忽略
+        };
+
+        let request = self
+            .client
+            .get(format!("{}/items/latest", config.endpoint));
+        let response = self
+            .authed(request, &config)
+            .send()
+            .await
+            .map_err(|e| ClipboardError::SyncError(e.to_string()))?;
+
+        let remote: Option<SyncPayload> = response
+            .json()
+            .await
+            .map_err(|e| ClipboardError::SyncError(e.to_string()))?;
+        let Some(remote) = remote else {
+            return Ok(());
+        };
+
+        // 先完成可能失败的解码，确保真正执行 set_text/set_image 之前
+        // 剩下的只是基础设施性的写入调用——避免哈希在内容从未写入时被提前“认领”，
+        // 否则对端会把这个解码失败的负载永远当成重复内容而静默丢弃。
+        // 文件名由图片内容的哈希派生，保证相同图片产生相同路径、不同图片产生不同路径，
+        // 这样 image_path 才能像本地捕获的条目一样参与去重，而不是让所有远程图片共享
+        // 同一个占位文本而哈希撞车。
+        let image_bytes = match remote.image_base64 {
+            Some(image_base64) => Some(
+                general_purpose::STANDARD
+                    .decode(image_base64)
+                    .map_err(|e| ClipboardError::SyncError(format!("解码图片失败: {}", e)))?,
+            ),
+            None => None,
+        };
+        let image_path = image_bytes.as_ref().map(|bytes| {
+            use std::collections::hash_map::DefaultHasher;
+            use std::hash::{Hash, Hasher};
+
+            let mut hasher = DefaultHasher::new();
+            bytes.hash(&mut hasher);
+            format!("clipboard_sync_{}.png", hasher.finish())
+        });
+
+        let mut state = self.state.lock().await;
+
+        let mut remote_item = state.create_item(
+            remote.content,
+            remote.content_type,
+            remote.html_content,
+            remote.rtf_content,
+            image_path.clone(),
+            None, // 同步负载不携带文件路径：远程路径在本机毫无意义，也从不会被写回
+            ClipboardKind::Clipboard,
+            Vec::new(), // 远程条目不来自本地剪贴板，无原始格式信息
+        );
+        remote_item.source = ClipboardSource::Remote;
+
+        let hash = state.calculate_hash(&remote_item);
+        if state.last_content_hash == Some(hash) {
+            return Ok(()); // 与本地已知内容相同，忽略
+        }
+
+        let ctx = ClipboardContext::new().map_err(|e| ClipboardError::InitError(e.to_string()))?;
+
+        let image = match image_bytes {
+            Some(bytes) => {
+                let image = RustImageData::from_bytes(&bytes)
+                    .map_err(|e| ClipboardError::SyncError(format!("解析图片失败: {}", e)))?;
+                // 像本地捕获的条目一样把图片落盘，这样远程同步来的条目也能通过
+                // get_clipboard_image 正常取回
+                let app_data_dir = self
+                    .app
+                    .path()
+                    .app_data_dir()
+                    .map_err(|e| ClipboardError::InitError(format!("无法获取应用数据目录: {}", e)))?;
+                let images_dir = app_data_dir.join("clipboard_images");
+                if !images_dir.exists() {
+                    fs::create_dir_all(&images_dir)
+                        .map_err(|e| ClipboardError::InitError(format!("无法创建图片目录: {}", e)))?;
+                }
+                let file_name = image_path
+                    .as_ref()
+                    .expect("image_path 必然在 image_bytes 存在时一并生成");
+                image
+                    .save_to_path(images_dir.join(file_name).to_str().unwrap())
+                    .map_err(|e| ClipboardError::SyncError(format!("保存图片失败: {}", e)))?;
+                Some(image)
+            }
+            None => None,
+        };
+
+        // 关键：必须在写入系统剪贴板之前更新哈希值，
+        // 这样 set_text/set_image 触发的 on_clipboard_change 会被识别为内容未变化，
+        // 不会被再次推送给对端，从而避免同步死循环。
+        state.last_content_hash = Some(hash);
+
+        match image {
+            Some(image) => ctx
+                .set_image(image)
+                .map_err(|e| ClipboardError::WriteError(e.to_string()))?,
+            None => ctx
+                .set_text(remote_item.content.clone())
+                .map_err(|e| ClipboardError::WriteError(e.to_string()))?,
+        }
+
+        let remote_item = state.add_item(remote_item);
+        drop(state);
+
+        self.app
+            .emit("clipboard-changed", remote_item)
+            .map_err(|e| ClipboardError::EventError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    // 后台轮询循环：按配置的间隔反复拉取对端的最新内容
+    async fn poll_loop(self: Arc<Self>) {
+        loop {
+            let interval = match self.config.lock().await.as_ref() {
+                Some(config) => config.poll_interval_secs,
+                None => 5, // 未配置时降低轮询频率，避免空转
+            };
+
+            if let Err(e) = self.poll_once().await {
+                error!("轮询远程剪贴板失败: {}", e);
+            }
+
+            tokio::time::sleep(Duration::from_secs(interval)).await;
+        }
+    }
+}
+
+// 后台轮询 X11 Primary Selection 的变化（clipboard-rs 的 watcher 只监听常规剪贴板，
+// Primary Selection 没有对应的变更通知机制，只能轮询）
+#[cfg(target_os = "linux")]
+async fn watch_primary_selection(state: Arc<Mutex<ClipboardState>>, app: tauri::AppHandle) {
+    loop {
+        tokio::time::sleep(Duration::from_millis(500)).await;
+
+        // read_primary_selection 会阻塞等待 X11 Selection Owner 响应（最长 3 秒），
+        // 必须放到阻塞线程池执行，否则会卡住驱动同步轮询和事件推送的 tokio 工作线程
+        let text = match tokio::task::spawn_blocking(read_primary_selection).await {
+            Ok(Ok(text)) if !text.is_empty() => text,
+            _ => continue,
+        };
+
+        let mut state_guard = state.lock().await;
+        let new_item = state_guard.create_item(
+            text,
+            ClipboardContentType::Text,
+            None,
+            None,
+            None,
+            None,
+            ClipboardKind::Primary,
+            Vec::new(), // Primary Selection 目前只按纯文本处理，不追踪格式列表
+        );
+
+        let hash = state_guard.calculate_hash(&new_item);
+        if state_guard.last_primary_hash == Some(hash) {
+            continue; // 内容未变化
+        }
+
+        let new_item = state_guard.add_item(new_item);
+        drop(state_guard);
+
+        if let Err(e) = app.emit("clipboard-changed", new_item) {
+            error!("发送 Primary Selection 变化事件失败: {}", e);
+        }
+    }
+}
+
 // 获取剪贴板历史记录
 #[tauri::command]
 fn get_clipboard_history(
@@ -321,7 +720,30 @@ fn get_clipboard_history(
 
 // 获取当前剪贴板内容
 #[tauri::command]
-fn get_clipboard_content() -> Result<ClipboardItem, String> {
+fn get_clipboard_content(kind: ClipboardKind) -> Result<ClipboardItem, String> {
+    // Primary Selection 只在 Linux/X11 下存在；其他平台透明回退到常规剪贴板
+    if kind == ClipboardKind::Primary && cfg!(target_os = "linux") {
+        let text = read_primary_selection().map_err(|e| e.to_string())?;
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+
+        return Ok(ClipboardItem {
+            id: 0, // 临时ID
+            content: text,
+            content_type: ClipboardContentType::Text,
+            html_content: None,
+            rtf_content: None,
+            image_path: None,
+            file_paths: None,
+            timestamp: now,
+            source: ClipboardSource::Local,
+            kind: ClipboardKind::Primary,
+            formats: Vec::new(), // Primary Selection 目前只按纯文本处理，不追踪格式列表
+        });
+    }
+
     let ctx = match ClipboardContext::new() {
         Ok(ctx) => ctx,
         Err(e) => return Err(format!("初始化剪贴板失败: {}", e)),
@@ -332,10 +754,29 @@ fn get_clipboard_content() -> Result<ClipboardItem, String> {
     let mut html_content = None;
     let mut rtf_content = None;
     let mut image_path = None;
+    let mut file_paths = None;
     let mut content_type = ClipboardContentType::Unknown;
 
     // 确定内容类型并获取相应内容
-    if ctx.has(ContentFormat::Image) {
+    if ctx.has(ContentFormat::Files) {
+        content_type = ClipboardContentType::File;
+        match ctx.get_files() {
+            Ok(paths) => {
+                let names: Vec<String> = paths
+                    .iter()
+                    .map(|p| {
+                        PathBuf::from(p)
+                            .file_name()
+                            .map(|n| n.to_string_lossy().to_string())
+                            .unwrap_or_else(|| p.clone())
+                    })
+                    .collect();
+                text = names.join(", ");
+                file_paths = Some(paths);
+            }
+            Err(e) => return Err(format!("获取剪贴板文件列表失败: {}", e)),
+        }
+    } else if ctx.has(ContentFormat::Image) {
         content_type = ClipboardContentType::Image;
         text = "[图片内容]".to_string();
         // 注意: 这里我们不保存图片，因为这只是读取当前内容
@@ -360,6 +801,9 @@ fn get_clipboard_content() -> Result<ClipboardItem, String> {
         }
     }
 
+    // 记录当前剪贴板持有的所有原始格式，便于调试
+    let formats = ctx.available_formats().unwrap_or_default();
+
     // 创建条目
     let now = SystemTime::now()
         .duration_since(UNIX_EPOCH)
@@ -373,7 +817,11 @@ fn get_clipboard_content() -> Result<ClipboardItem, String> {
         html_content,
         rtf_content,
         image_path,
+        file_paths,
         timestamp: now,
+        source: ClipboardSource::Local,
+        kind: ClipboardKind::Clipboard,
+        formats,
     })
 }
 
@@ -393,13 +841,58 @@ fn get_clipboard_image(app: tauri::AppHandle, image_name: String) -> Result<Vec<
     fs::read(image_path).map_err(|e| format!("读取图片文件失败: {}", e))
 }
 
+// 获取当前剪贴板持有的所有原始格式标识
+#[tauri::command]
+fn get_available_formats() -> Result<Vec<String>, String> {
+    let ctx = match ClipboardContext::new() {
+        Ok(ctx) => ctx,
+        Err(e) => return Err(format!("初始化剪贴板失败: {}", e)),
+    };
+
+    ctx.available_formats()
+        .map_err(|e| format!("获取剪贴板格式列表失败: {}", e))
+}
+
+// 按格式标识读取剪贴板的原始数据
+#[tauri::command]
+fn get_clipboard_raw(format: String) -> Result<Vec<u8>, String> {
+    let ctx = match ClipboardContext::new() {
+        Ok(ctx) => ctx,
+        Err(e) => return Err(format!("初始化剪贴板失败: {}", e)),
+    };
+
+    ctx.get_buffer(&format)
+        .map_err(|e| format!("读取剪贴板原始数据失败: {}", e))
+}
+
+// 按格式标识写入剪贴板的原始数据
+#[tauri::command]
+fn set_clipboard_raw(format: String, data: Vec<u8>) -> Result<(), String> {
+    let ctx = match ClipboardContext::new() {
+        Ok(ctx) => ctx,
+        Err(e) => return Err(format!("初始化剪贴板失败: {}", e)),
+    };
+
+    ctx.set_buffer(&format, data)
+        .map_err(|e| format!("写入剪贴板原始数据失败: {}", e))
+}
+
 // 设置剪贴板内容
 #[tauri::command]
 fn set_clipboard_content(
+    app: tauri::AppHandle,
     content: String,
     html_content: Option<String>,
     rtf_content: Option<String>,
+    file_paths: Option<Vec<String>>,
+    image_path: Option<String>,
+    kind: ClipboardKind,
 ) -> Result<(), String> {
+    // Primary Selection 只在 Linux/X11 下存在；其他平台透明回退到常规剪贴板
+    if kind == ClipboardKind::Primary && cfg!(target_os = "linux") {
+        return write_primary_selection(content).map_err(|e| e.to_string());
+    }
+
     let ctx = match ClipboardContext::new() {
         Ok(ctx) => ctx,
         Err(e) => return Err(format!("初始化剪贴板失败: {}", e)),
@@ -426,9 +919,68 @@ fn set_clipboard_content(
         }
     }
 
+    // 如果有文件列表，也写回剪贴板
+    if let Some(paths) = file_paths {
+        if let Err(e) = ctx.set_files(paths) {
+            debug!("设置文件列表失败: {}", e);
+            // 继续执行，不返回错误
+        }
+    }
+
+    // 如果同时带有图片，也一并写回剪贴板，使文本与图片共存
+    if let Some(image_name) = image_path {
+        if let Err(e) = load_and_set_image(&ctx, &app, &image_name) {
+            debug!("设置图片内容失败: {}", e);
+            // 继续执行，不返回错误
+        }
+    }
+
     Ok(())
 }
 
+// 从 clipboard_images 目录加载指定图片并写入剪贴板
+fn load_and_set_image(
+    ctx: &ClipboardContext,
+    app: &tauri::AppHandle,
+    image_name: &str,
+) -> Result<(), ClipboardError> {
+    // image_name 必须是 clipboard_images 目录下的单个文件名，
+    // 拒绝路径分隔符以防止越出该目录读取任意文件
+    if image_name.contains('/') || image_name.contains('\\') {
+        return Err(ClipboardError::ReadError(format!(
+            "非法的图片文件名: {}",
+            image_name
+        )));
+    }
+
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| ClipboardError::InitError(format!("无法获取应用数据目录: {}", e)))?;
+    let image_path = app_data_dir.join("clipboard_images").join(image_name);
+
+    let image = RustImageData::from_path(
+        image_path
+            .to_str()
+            .ok_or_else(|| ClipboardError::ReadError("图片路径包含非法字符".to_string()))?,
+    )
+    .map_err(|e| ClipboardError::ReadError(format!("读取图片失败: {}", e)))?;
+
+    ctx.set_image(image)
+        .map_err(|e| ClipboardError::WriteError(e.to_string()))
+}
+
+// 把保存在历史记录中的图片重新写入系统剪贴板
+#[tauri::command]
+fn set_clipboard_image(app: tauri::AppHandle, image_name: String) -> Result<(), String> {
+    let ctx = match ClipboardContext::new() {
+        Ok(ctx) => ctx,
+        Err(e) => return Err(format!("初始化剪贴板失败: {}", e)),
+    };
+
+    load_and_set_image(&ctx, &app, &image_name).map_err(|e| e.to_string())
+}
+
 // 清空剪贴板历史
 #[tauri::command]
 fn clear_clipboard_history(state: State<Arc<Mutex<ClipboardState>>>) -> Result<(), String> {
@@ -490,6 +1042,53 @@ fn set_max_history_size(
     }
 }
 
+// 启动/更新剪贴板网络同步
+#[tauri::command]
+fn start_sync(
+    endpoint: String,
+    token: Option<String>,
+    poll_interval_secs: u64,
+    sync: State<Arc<ClipboardSync>>,
+) -> Result<(), String> {
+    let rt = match tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+    {
+        Ok(rt) => rt,
+        Err(e) => return Err(format!("创建运行时失败: {}", e)),
+    };
+
+    rt.block_on(async {
+        let mut config = sync.config.lock().await;
+        *config = Some(SyncConfig {
+            endpoint,
+            token,
+            poll_interval_secs,
+        });
+    });
+
+    Ok(())
+}
+
+// 停止剪贴板网络同步
+#[tauri::command]
+fn stop_sync(sync: State<Arc<ClipboardSync>>) -> Result<(), String> {
+    let rt = match tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+    {
+        Ok(rt) => rt,
+        Err(e) => return Err(format!("创建运行时失败: {}", e)),
+    };
+
+    rt.block_on(async {
+        let mut config = sync.config.lock().await;
+        *config = None;
+    });
+
+    Ok(())
+}
+
 // 主入口点
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
@@ -513,7 +1112,7 @@ pub fn run() {
             // 创建剪贴板监听器
             let app_handle = app.app_handle();
             let clipboard_manager =
-                match ClipboardStateManager::new(clipboard_state, app_handle.clone()) {
+                match ClipboardStateManager::new(clipboard_state.clone(), app_handle.clone()) {
                     Ok(manager) => manager,
                     Err(e) => {
                         error!("创建剪贴板管理器失败: {}", e);
@@ -539,15 +1138,53 @@ pub fn run() {
                 watcher.start_watch();
             });
 
+            // 在 Linux 下额外轮询 Primary Selection 的变化
+            #[cfg(target_os = "linux")]
+            tauri::async_runtime::spawn(watch_primary_selection(
+                clipboard_state.clone(),
+                app_handle.clone(),
+            ));
+
+            // 初始化网络同步子系统
+            let clipboard_sync = Arc::new(ClipboardSync::new(clipboard_state, app_handle.clone()));
+            app.manage(clipboard_sync.clone());
+
+            // 监听本地剪贴板变化事件，把新内容推送给对端
+            // （只推送本地产生的条目，避免把刚同步回来的远程条目又推送回去）
+            let push_sync = clipboard_sync.clone();
+            app.listen("clipboard-changed", move |event| {
+                let push_sync = push_sync.clone();
+                let Ok(item) = serde_json::from_str::<ClipboardItem>(event.payload()) else {
+                    return;
+                };
+                if item.source != ClipboardSource::Local {
+                    return;
+                }
+                tauri::async_runtime::spawn(async move {
+                    if let Err(e) = push_sync.push_item(&item).await {
+                        error!("推送剪贴板内容到对端失败: {}", e);
+                    }
+                });
+            });
+
+            // 启动后台轮询任务，定期拉取对端的最新剪贴板内容
+            tauri::async_runtime::spawn(clipboard_sync.poll_loop());
+
             Ok(())
         })
         .invoke_handler(tauri::generate_handler![
             get_clipboard_content,
             set_clipboard_content,
+            start_sync,
+            stop_sync,
             get_clipboard_history,
             clear_clipboard_history,
             set_max_history_size,
             get_clipboard_image,
+            get_available_formats,
+            get_clipboard_raw,
+            set_clipboard_raw,
+            set_clipboard_image,
         ])
         .run(tauri::generate_context!())
         .context("运行Tauri应用失败")